@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
+  collections::HashMap,
   ffi::{c_void, CStr},
   path::PathBuf,
 };
@@ -15,20 +16,96 @@ use objc2::{
   runtime::{Bool, Object, Sel},
   sel,
 };
-use objc2_app_kit::{NSFilenamesPboardType, NSPasteboard, NSPasteboardType};
-use objc2_foundation::{NSArray, NSPoint, NSRect, NSString};
+use objc2_app_kit::{
+  NSFilenamesPboardType, NSPasteboard, NSPasteboardType, NSPasteboardTypeString,
+  NSPasteboardTypeURL,
+};
+use objc2_foundation::{NSArray, NSObject, NSPoint, NSRect, NSString};
 use once_cell::sync::Lazy;
 
 use crate::DragDropEvent;
 
 use super::util::id;
 
+/// The decoded contents of a drag-and-drop pasteboard: a single drag may carry files, plain
+/// text, a URL, and an HTML fragment all at once, so every flavor present is read out.
+#[derive(Debug, Default, Clone)]
+pub struct DropData {
+  pub paths: Vec<PathBuf>,
+  pub text: Option<String>,
+  pub urls: Vec<String>,
+  pub html: Option<String>,
+  /// Values decoded by any registered [`DragDataAdapter`]s, keyed by the pasteboard format
+  /// each adapter reported handling.
+  pub custom: HashMap<String, DragPayload>,
+}
+
+/// A value decoded from a custom pasteboard format by a [`DragDataAdapter`].
+#[derive(Debug, Clone)]
+pub enum DragPayload {
+  Bytes(Vec<u8>),
+  String(String),
+}
+
+/// Decodes (and, for a future drag-out path, encodes) an app-specific `NSPasteboardType` that
+/// `collect_paths`/`collect_drop_data` don't know about — app UTIs, file promises, RTF, etc.
+pub trait DragDataAdapter: Send + Sync {
+  /// The `NSPasteboardType` (or UTI) this adapter decodes.
+  fn format(&self) -> &str;
+
+  /// Attempt to decode this adapter's payload out of the dragging pasteboard.
+  fn retrieve(&self, pasteboard: &NSPasteboard) -> Option<DragPayload>;
+
+  /// Write `payload` onto `pasteboard` for a drag-out. Unused until wry supports initiating
+  /// drags; the default no-op keeps existing adapters (which only need `retrieve`) compiling.
+  fn prepare(&self, _pasteboard: &NSPasteboard, _payload: &DragPayload) {}
+}
+
+const DRAG_DROP_ADAPTERS_IVAR: &str = "DragDropAdapters";
+
 pub(crate) type NSDragOperation = objc2_foundation::NSUInteger;
 
+#[allow(non_upper_case_globals)]
+const NSDragOperationNone: NSDragOperation = 0;
 #[allow(non_upper_case_globals)]
 const NSDragOperationCopy: NSDragOperation = 1;
+#[allow(non_upper_case_globals)]
+const NSDragOperationLink: NSDragOperation = 2;
+#[allow(non_upper_case_globals)]
+const NSDragOperationGeneric: NSDragOperation = 4;
+#[allow(non_upper_case_globals)]
+const NSDragOperationMove: NSDragOperation = 16;
+
+/// The effect a [`DragDropEvent`] handler wants the drag to have, mirroring the subset of
+/// `NSDragOperation` that WebKit surfaces to the cursor and to `performDragOperation:`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragOperation {
+  /// Leave the drag to the OS/WebKit default behavior. While hovering, a WebKit result of
+  /// `NSDragOperationNone` is still reported as `NSDragOperationCopy`.
+  None,
+  Copy,
+  Move,
+  Link,
+  Generic,
+}
+
+impl DragOperation {
+  fn to_ns_drag_operation(self) -> NSDragOperation {
+    match self {
+      DragOperation::None => NSDragOperationNone,
+      DragOperation::Copy => NSDragOperationCopy,
+      DragOperation::Move => NSDragOperationMove,
+      DragOperation::Link => NSDragOperationLink,
+      DragOperation::Generic => NSDragOperationGeneric,
+    }
+  }
+}
 
 const DRAG_DROP_HANDLER_IVAR: &str = "DragDropHandler";
+// Stores the *inverted* flag (whether to hide files from JS) so that an untouched ivar —
+// which the Objective-C runtime zero-initializes — defaults to exposing files, matching the
+// pre-existing behavior.
+const DRAG_DROP_HIDE_FILES_IVAR: &str = "DragDropHideFilesFromJS";
 
 static OBJC_DRAGGING_ENTERED: Lazy<extern "C" fn(*const Object, Sel, id) -> NSDragOperation> =
   Lazy::new(|| unsafe {
@@ -72,93 +149,196 @@ static OBJC_DRAGGING_UPDATED: Lazy<extern "C" fn(*const Object, Sel, id) -> NSDr
 // Safety: objc runtime calls are unsafe
 pub(crate) unsafe fn set_drag_drop_handler(
   webview: *mut Object,
-  handler: Box<dyn Fn(DragDropEvent) -> bool>,
-) -> *mut Box<dyn Fn(DragDropEvent) -> bool> {
+  handler: Box<dyn Fn(DragDropEvent) -> DragOperation>,
+) -> *mut Box<dyn Fn(DragDropEvent) -> DragOperation> {
   let listener = Box::into_raw(Box::new(handler));
   *(*webview).get_mut_ivar(DRAG_DROP_HANDLER_IVAR) = listener as *mut _ as *mut c_void;
   listener
 }
 
 #[allow(clippy::mut_from_ref)]
-unsafe fn get_handler(this: &Object) -> &mut Box<dyn Fn(DragDropEvent) -> bool> {
+unsafe fn get_handler(this: &Object) -> &mut Box<dyn Fn(DragDropEvent) -> DragOperation> {
   let delegate: *mut c_void = *this.get_ivar(DRAG_DROP_HANDLER_IVAR);
-  &mut *(delegate as *mut Box<dyn Fn(DragDropEvent) -> bool>)
+  &mut *(delegate as *mut Box<dyn Fn(DragDropEvent) -> DragOperation>)
 }
 
-unsafe fn collect_paths(drag_info: id) -> Vec<PathBuf> {
-  let pb: Id<NSPasteboard> = msg_send_id![drag_info, draggingPasteboard];
+// Safety: objc runtime calls are unsafe
+pub(crate) unsafe fn set_drag_drop_adapters(
+  webview: *mut Object,
+  adapters: Vec<Box<dyn DragDataAdapter>>,
+) -> *mut Vec<Box<dyn DragDataAdapter>> {
+  let adapters = Box::into_raw(Box::new(adapters));
+  *(*webview).get_mut_ivar(DRAG_DROP_ADAPTERS_IVAR) = adapters as *mut c_void;
+  adapters
+}
+
+unsafe fn get_adapters(this: &Object) -> &[Box<dyn DragDataAdapter>] {
+  let adapters: *mut c_void = *this.get_ivar(DRAG_DROP_ADAPTERS_IVAR);
+  if adapters.is_null() {
+    &[]
+  } else {
+    &*(adapters as *const Vec<Box<dyn DragDataAdapter>>)
+  }
+}
+
+// Safety: objc runtime calls are unsafe
+pub(crate) unsafe fn set_drag_drop_file_access(webview: *mut Object, enabled: bool) {
+  *(*webview).get_mut_ivar(DRAG_DROP_HIDE_FILES_IVAR) = Bool::new(!enabled);
+}
+
+unsafe fn hide_files_from_js(this: &Object) -> bool {
+  let hide: Bool = *this.get_ivar(DRAG_DROP_HIDE_FILES_IVAR);
+  hide.as_bool()
+}
+
+// Overwrite the filenames entry on the shared dragging pasteboard so nothing downstream of
+// us — including WebKit's own drag handling, which reads from this same pasteboard — can
+// still observe it and synthesize a `Files` entry in the page's `DataTransfer`. Controlling
+// our own delegate's return value isn't enough, since WebKit tracks the drag independently.
+unsafe fn hide_filenames_on_pasteboard(pb: &NSPasteboard) {
+  let empty: Id<NSArray<NSString>> = msg_send_id![class!(NSArray), new];
+  let _: Bool = msg_send![pb, setPropertyList: &*empty, forType: NSFilenamesPboardType];
+}
+
+unsafe fn collect_paths(pb: &NSPasteboard) -> Vec<PathBuf> {
   let mut drag_drop_paths = Vec::new();
   let types: Id<NSArray<NSPasteboardType>> =
     msg_send_id![class!(NSArray), arrayWithObject: NSFilenamesPboardType];
-  if let Some(_) = pb.availableTypeFromArray(&types) {
-    for path in pb.propertyListForType(NSFilenamesPboardType) {
-      let path: Id<NSString> = Id::cast(path);
-      drag_drop_paths.push(PathBuf::from(
-        CStr::from_ptr(path.UTF8String())
-          .to_string_lossy()
-          .into_owned(),
-      ));
-    }
+  if pb.availableTypeFromArray(&types).is_none() {
+    return drag_drop_paths;
+  }
+
+  // Some synthetic drags and promised-file drops advertise the filenames type but hand back a
+  // nil or non-array property list; bail out to an empty list instead of panicking on the cast
+  // below.
+  let property_list: *mut Object = msg_send![pb, propertyListForType: NSFilenamesPboardType];
+  if property_list.is_null() {
+    return drag_drop_paths;
+  }
+  let is_array: Bool = msg_send![property_list, isKindOfClass: class!(NSArray)];
+  if !is_array.as_bool() {
+    return drag_drop_paths;
+  }
+
+  let property_list = property_list as *mut NSArray<NSObject>;
+  for path in (*property_list).iter_retained() {
+    let path: Id<NSString> = Id::cast(path);
+    drag_drop_paths.push(PathBuf::from(
+      CStr::from_ptr(path.UTF8String())
+        .to_string_lossy()
+        .into_owned(),
+    ));
   }
   drag_drop_paths
 }
 
+// Each pasteboard flavor is decoded independently and every flavor that's present is
+// surfaced, rather than picking only the richest one.
+unsafe fn collect_drop_data(drag_info: id, adapters: &[Box<dyn DragDataAdapter>]) -> DropData {
+  let pb: Id<NSPasteboard> = msg_send_id![drag_info, draggingPasteboard];
+
+  let paths = collect_paths(&pb);
+
+  let text = pb.stringForType(NSPasteboardTypeString).map(|s| s.to_string());
+
+  let mut urls = Vec::new();
+  if let Some(url) = pb.stringForType(NSPasteboardTypeURL) {
+    urls.push(url.to_string());
+  } else if let Some(url) = pb.stringForType(&NSString::from_str("NSURLPboardType")) {
+    urls.push(url.to_string());
+  }
+
+  let html = pb
+    .stringForType(&NSString::from_str("public.html"))
+    .map(|s| s.to_string());
+
+  let mut custom = HashMap::new();
+  for adapter in adapters {
+    if let Some(payload) = adapter.retrieve(&pb) {
+      custom.insert(adapter.format().to_string(), payload);
+    }
+  }
+
+  DropData {
+    paths,
+    text,
+    urls,
+    html,
+    custom,
+  }
+}
+
 extern "C" fn dragging_updated(this: &mut Object, sel: Sel, drag_info: id) -> NSDragOperation {
   let dl: NSPoint = unsafe { msg_send![drag_info, draggingLocation] };
   let frame: NSRect = unsafe { msg_send![this, frame] };
   let position = (dl.x as i32, (frame.size.height - dl.y) as i32);
   let listener = unsafe { get_handler(this) };
-  if !listener(DragDropEvent::Over { position }) {
-    let os_operation = OBJC_DRAGGING_UPDATED(this, sel, drag_info);
-    if os_operation == 0 {
-      // 0 will be returned for a drop on any arbitrary location on the webview.
-      // We'll override that with NSDragOperationCopy.
-      NSDragOperationCopy
-    } else {
-      // A different NSDragOperation is returned when a file is hovered over something like
-      // a <input type="file">, so we'll make sure to preserve that behaviour.
-      os_operation
+  match listener(DragDropEvent::Over { position }) {
+    DragOperation::None => {
+      let os_operation = OBJC_DRAGGING_UPDATED(this, sel, drag_info);
+      if os_operation == 0 {
+        // 0 will be returned for a drop on any arbitrary location on the webview.
+        // We'll override that with NSDragOperationCopy.
+        NSDragOperationCopy
+      } else {
+        // A different NSDragOperation is returned when a file is hovered over something like
+        // a <input type="file">, so we'll make sure to preserve that behaviour.
+        os_operation
+      }
     }
-  } else {
-    NSDragOperationCopy
+    operation => operation.to_ns_drag_operation(),
   }
 }
 
 extern "C" fn dragging_entered(this: &mut Object, sel: Sel, drag_info: id) -> NSDragOperation {
   let listener = unsafe { get_handler(this) };
-  let paths = unsafe { collect_paths(drag_info) };
+  let data = unsafe { collect_drop_data(drag_info, get_adapters(this)) };
 
   let dl: NSPoint = unsafe { msg_send![drag_info, draggingLocation] };
   let frame: NSRect = unsafe { msg_send![this, frame] };
   let position = (dl.x as i32, (frame.size.height - dl.y) as i32);
 
-  if !listener(DragDropEvent::Enter { paths, position }) {
-    // Reject the Wry file drop (invoke the OS default behaviour)
-    OBJC_DRAGGING_ENTERED(this, sel, drag_info)
-  } else {
-    NSDragOperationCopy
+  match listener(DragDropEvent::Enter { data, position }) {
+    DragOperation::None => {
+      // Reject the Wry file drop (invoke the OS default behaviour)
+      OBJC_DRAGGING_ENTERED(this, sel, drag_info)
+    }
+    // The filenames entry isn't scrubbed here: Enter and Drop share the same session
+    // pasteboard, and `perform_drag_operation` still needs to read real paths out of it for
+    // the native handler this feature is meant to let through.
+    operation => operation.to_ns_drag_operation(),
   }
 }
 
 extern "C" fn perform_drag_operation(this: &mut Object, sel: Sel, drag_info: id) -> Bool {
   let listener = unsafe { get_handler(this) };
-  let paths = unsafe { collect_paths(drag_info) };
+  let data = unsafe { collect_drop_data(drag_info, get_adapters(this)) };
+  let hides_files = unsafe { hide_files_from_js(this) } && !data.paths.is_empty();
 
   let dl: NSPoint = unsafe { msg_send![drag_info, draggingLocation] };
   let frame: NSRect = unsafe { msg_send![this, frame] };
   let position = (dl.x as i32, (frame.size.height - dl.y) as i32);
 
-  if !listener(DragDropEvent::Drop { paths, position }) {
-    // Reject the Wry drop (invoke the OS default behaviour)
-    OBJC_PERFORM_DRAG_OPERATION(this, sel, drag_info)
-  } else {
-    Bool::YES
+  match listener(DragDropEvent::Drop { data, position }) {
+    DragOperation::None => {
+      // Reject the Wry drop (invoke the OS default behaviour)
+      OBJC_PERFORM_DRAG_OPERATION(this, sel, drag_info)
+    }
+    _ => {
+      if hides_files {
+        unsafe {
+          let pb: Id<NSPasteboard> = msg_send_id![drag_info, draggingPasteboard];
+          hide_filenames_on_pasteboard(&pb);
+        }
+      }
+      Bool::YES
+    }
   }
 }
 
 extern "C" fn dragging_exited(this: &mut Object, sel: Sel, drag_info: id) {
   let listener = unsafe { get_handler(this) };
-  if !listener(DragDropEvent::Leave) {
+  if listener(DragDropEvent::Leave) == DragOperation::None {
     // Reject the Wry drop (invoke the OS default behaviour)
     OBJC_DRAGGING_EXITED(this, sel, drag_info);
   }
@@ -166,6 +346,8 @@ extern "C" fn dragging_exited(this: &mut Object, sel: Sel, drag_info: id) {
 
 pub(crate) unsafe fn add_drag_drop_methods(decl: &mut ClassDecl) {
   decl.add_ivar::<*mut c_void>(DRAG_DROP_HANDLER_IVAR);
+  decl.add_ivar::<*mut c_void>(DRAG_DROP_ADAPTERS_IVAR);
+  decl.add_ivar::<Bool>(DRAG_DROP_HIDE_FILES_IVAR);
 
   decl.add_method(
     sel!(draggingEntered:),
@@ -187,3 +369,163 @@ pub(crate) unsafe fn add_drag_drop_methods(decl: &mut ClassDecl) {
     dragging_exited as extern "C" fn(&mut Object, Sel, id),
   );
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // The backing ivar is zero-initialized by the Objective-C runtime, so a webview that never
+  // calls `set_drag_drop_file_access` must still expose dropped files to JS.
+  #[test]
+  fn file_access_is_enabled_by_default() {
+    unsafe {
+      let mut decl = ClassDecl::new("WryDragDropFileAccessDefaultTest", class!(NSObject)).unwrap();
+      decl.add_ivar::<Bool>(DRAG_DROP_HIDE_FILES_IVAR);
+      let cls = decl.register();
+      let obj: Id<Object> = msg_send_id![cls, new];
+      assert!(!hide_files_from_js(&obj));
+    }
+  }
+
+  // Exercises the mechanism a handled drop relies on to keep files out of the page's
+  // DataTransfer: overwriting the filenames entry on the live dragging pasteboard, since
+  // WebKit reads that pasteboard on its own regardless of what our delegate returns.
+  #[test]
+  fn hide_filenames_on_pasteboard_clears_the_filenames_entry() {
+    unsafe {
+      let name = NSString::from_str("WryDragDropHideFilesTestPasteboard");
+      let pb: Id<NSPasteboard> = msg_send_id![class!(NSPasteboard), pasteboardWithName: &*name];
+      let types: Id<NSArray<NSPasteboardType>> =
+        msg_send_id![class!(NSArray), arrayWithObject: NSFilenamesPboardType];
+      let _: isize = msg_send![&pb, declareTypes: &*types, owner: std::ptr::null::<Object>()];
+      let path: Id<NSString> = NSString::from_str("/tmp/dropped-file.txt");
+      let list: Id<NSArray<NSString>> = msg_send_id![class!(NSArray), arrayWithObject: &*path];
+      let _: Bool = msg_send![&pb, setPropertyList: &*list, forType: NSFilenamesPboardType];
+
+      assert_eq!(collect_paths(&pb), vec![PathBuf::from("/tmp/dropped-file.txt")]);
+
+      hide_filenames_on_pasteboard(&pb);
+
+      assert!(collect_paths(&pb).is_empty());
+    }
+  }
+
+  // The core extension point of `DragDataAdapter`: a registered adapter's payload should show
+  // up in `DropData::custom`, keyed by the format it reported handling.
+  #[test]
+  fn custom_adapter_payload_is_merged_into_drop_data() {
+    struct FakeAdapter;
+    impl DragDataAdapter for FakeAdapter {
+      fn format(&self) -> &str {
+        "com.example.fake"
+      }
+
+      fn retrieve(&self, _pasteboard: &NSPasteboard) -> Option<DragPayload> {
+        Some(DragPayload::String("fake-payload".into()))
+      }
+    }
+
+    unsafe {
+      let name = NSString::from_str("WryDragDropAdapterTestPasteboard");
+      let pb: Id<NSPasteboard> = msg_send_id![class!(NSPasteboard), pasteboardWithName: &*name];
+
+      let mut decl = ClassDecl::new("WryDragDropAdapterTestDragInfo", class!(NSObject)).unwrap();
+      decl.add_ivar::<*mut c_void>("Pasteboard");
+      extern "C" fn dragging_pasteboard(this: *mut Object, _sel: Sel) -> *mut c_void {
+        unsafe { *(*this).get_ivar("Pasteboard") }
+      }
+      decl.add_method(
+        sel!(draggingPasteboard),
+        dragging_pasteboard as extern "C" fn(*mut Object, Sel) -> *mut c_void,
+      );
+      let cls = decl.register();
+      let drag_info: *mut Object = msg_send![cls, new];
+      *(*drag_info).get_mut_ivar("Pasteboard") = &*pb as *const NSPasteboard as *mut c_void;
+
+      let adapters: Vec<Box<dyn DragDataAdapter>> = vec![Box::new(FakeAdapter)];
+      let data = collect_drop_data(drag_info as id, &adapters);
+
+      assert_eq!(data.paths, Vec::<PathBuf>::new());
+      match data.custom.get("com.example.fake") {
+        Some(DragPayload::String(value)) => assert_eq!(value, "fake-payload"),
+        other => panic!("expected a fake-payload string, got {:?}", other),
+      }
+    }
+  }
+
+  // Regression test for synthetic drags that advertise the filenames type but back it with a
+  // non-array property list; `collect_paths` must bail out instead of panicking on the cast.
+  #[test]
+  fn collect_paths_ignores_non_array_property_list() {
+    unsafe {
+      let name = NSString::from_str("WryDragDropNonArrayTestPasteboard");
+      let pb: Id<NSPasteboard> = msg_send_id![class!(NSPasteboard), pasteboardWithName: &*name];
+      let types: Id<NSArray<NSPasteboardType>> =
+        msg_send_id![class!(NSArray), arrayWithObject: NSFilenamesPboardType];
+      let _: isize = msg_send![&pb, declareTypes: &*types, owner: std::ptr::null::<Object>()];
+      let bogus: Id<NSString> = NSString::from_str("not-an-array");
+      let _: Bool = msg_send![&pb, setPropertyList: &*bogus, forType: NSFilenamesPboardType];
+
+      assert!(collect_paths(&pb).is_empty());
+    }
+  }
+
+  // Exercises the multi-flavor decode path in `collect_drop_data`: text, URL, and HTML
+  // flavors are all read out of the same drag, not just whichever is richest.
+  #[test]
+  fn collect_drop_data_decodes_text_url_and_html() {
+    unsafe {
+      let name = NSString::from_str("WryDragDropMultiFlavorTestPasteboard");
+      let pb: Id<NSPasteboard> = msg_send_id![class!(NSPasteboard), pasteboardWithName: &*name];
+      let html_type = NSString::from_str("public.html");
+      let string_type: Id<NSArray<NSPasteboardType>> =
+        msg_send_id![class!(NSArray), arrayWithObject: NSPasteboardTypeString];
+      let _: isize = msg_send![&pb, declareTypes: &*string_type, owner: std::ptr::null::<Object>()];
+      let url_type: Id<NSArray<NSPasteboardType>> =
+        msg_send_id![class!(NSArray), arrayWithObject: NSPasteboardTypeURL];
+      pb.addTypes_owner(&url_type, None);
+      let html_types: Id<NSArray<NSPasteboardType>> =
+        msg_send_id![class!(NSArray), arrayWithObject: &*html_type];
+      pb.addTypes_owner(&html_types, None);
+      let text = NSString::from_str("hello drag");
+      let _: Bool = msg_send![&pb, setString: &*text, forType: NSPasteboardTypeString];
+      let url = NSString::from_str("https://example.com");
+      let _: Bool = msg_send![&pb, setString: &*url, forType: NSPasteboardTypeURL];
+      let html = NSString::from_str("<b>hi</b>");
+      let _: Bool = msg_send![&pb, setString: &*html, forType: &*html_type];
+
+      let mut decl = ClassDecl::new("WryDragDropMultiFlavorTestDragInfo", class!(NSObject)).unwrap();
+      decl.add_ivar::<*mut c_void>("Pasteboard");
+      extern "C" fn dragging_pasteboard(this: *mut Object, _sel: Sel) -> *mut c_void {
+        unsafe { *(*this).get_ivar("Pasteboard") }
+      }
+      decl.add_method(
+        sel!(draggingPasteboard),
+        dragging_pasteboard as extern "C" fn(*mut Object, Sel) -> *mut c_void,
+      );
+      let cls = decl.register();
+      let drag_info: *mut Object = msg_send![cls, new];
+      *(*drag_info).get_mut_ivar("Pasteboard") = &*pb as *const NSPasteboard as *mut c_void;
+
+      let data = collect_drop_data(drag_info as id, &[]);
+
+      assert_eq!(data.text.as_deref(), Some("hello drag"));
+      assert_eq!(data.urls, vec!["https://example.com".to_string()]);
+      assert_eq!(data.html.as_deref(), Some("<b>hi</b>"));
+    }
+  }
+
+  // Each `DragOperation` a handler can return must map to its matching `NSDragOperation` bit,
+  // not just the Copy/None pair the original accept-or-reject API covered.
+  #[test]
+  fn drag_operation_maps_to_the_matching_ns_drag_operation_bit() {
+    assert_eq!(DragOperation::None.to_ns_drag_operation(), NSDragOperationNone);
+    assert_eq!(DragOperation::Copy.to_ns_drag_operation(), NSDragOperationCopy);
+    assert_eq!(DragOperation::Move.to_ns_drag_operation(), NSDragOperationMove);
+    assert_eq!(DragOperation::Link.to_ns_drag_operation(), NSDragOperationLink);
+    assert_eq!(
+      DragOperation::Generic.to_ns_drag_operation(),
+      NSDragOperationGeneric
+    );
+  }
+}